@@ -0,0 +1,8 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A splittable pseudorandom number generator, as described in
+//! Steele, Lea, and Flood's OOPSLA '14 paper.
+
+mod splitmix64;
+
+pub use splitmix64::*;
@@ -1,7 +1,9 @@
+use core::cell::UnsafeCell;
 use rand_core::impls::fill_bytes_via_next;
 use rand_core::le::read_u64_into;
 use rand_core::{Error, RngCore, SeedableRng};
-use std::cell::UnsafeCell;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
 
 const GOLDEN_GAMMA: u64 = 0x9e3779b97f4a7c15;
 
@@ -21,6 +23,7 @@ const GOLDEN_GAMMA: u64 = 0x9e3779b97f4a7c15;
 /// of pseudorandom values are too predictable (the mixing functions are easily inverted, and two
 /// successive outputs suffice to reconstruct the internal state).
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
 pub struct SMGen {
     seed: u64,
     gamma: u64,
@@ -38,6 +41,69 @@ impl SMGen {
             },
         }
     }
+
+    /// Advance the generator as if `next_u64` had been called `n` times, in constant time
+    ///
+    /// Since every call to `next_u64` advances the internal seed by the fixed `gamma`, skipping
+    /// ahead is a single multiply-add rather than `n` iterations. This is analogous to the
+    /// `jump()`/`long_jump()` functions xoshiro generators expose for carving out non-overlapping
+    /// subsequences for parallel workers.
+    pub fn advance(&mut self, n: u64) {
+        self.seed = self.seed.wrapping_add(self.gamma.wrapping_mul(n));
+    }
+
+    /// Rewind the generator as if `next_u64` had been called `n` fewer times, in constant time
+    ///
+    /// The inverse of [`advance`](Self::advance).
+    pub fn rewind(&mut self, n: u64) {
+        self.seed = self.seed.wrapping_sub(self.gamma.wrapping_mul(n));
+    }
+
+    /// Advance the generator past `n` calls to [`split`](Self::split)
+    ///
+    /// Each `split()` call advances the seed by two gamma-steps, so this is equivalent to (but
+    /// cheaper than) calling `split()` `n` times and discarding the results.
+    pub fn advance_splits(&mut self, n: u64) {
+        self.advance(n.wrapping_mul(2));
+    }
+
+    /// Rewind the generator past `n` calls to [`split`](Self::split)
+    ///
+    /// The inverse of [`advance_splits`](Self::advance_splits).
+    pub fn rewind_splits(&mut self, n: u64) {
+        self.rewind(n.wrapping_mul(2));
+    }
+
+    /// Compute the number of `next_u64` calls needed to advance from `self`'s state to `other`'s
+    ///
+    /// Returns `None` if the two generators do not share the same `gamma`, since distance is only
+    /// well-defined within a single stream. Because `mix_gamma` always sets the low bit, `gamma`
+    /// is odd and thus invertible mod 2^64, so the distance can be recovered in constant time via
+    /// a Newton-Raphson inverse instead of a linear search.
+    pub fn distance(&self, other: &Self) -> Option<u64> {
+        if self.gamma != other.gamma {
+            return None;
+        }
+        Some(other.seed.wrapping_sub(self.seed).wrapping_mul(inverse_odd(self.gamma)))
+    }
+
+    /// An unbounded iterator of pairwise-uncorrelated sub-generators
+    ///
+    /// Each item is produced by calling [`split`](Self::split) on `self`, so the iterator never
+    /// runs dry. This gives divide-and-conquer callers a deterministic, reproducible generator per
+    /// task, e.g. `gen.split_iter().zip(tasks).par_bridge()...` with Rayon.
+    pub fn split_iter(&mut self) -> impl Iterator<Item = Self> + '_ {
+        core::iter::from_fn(move || Some(self.split()))
+    }
+}
+
+/// Compute the multiplicative inverse of an odd `u64` modulo 2^64 via Newton's iteration
+fn inverse_odd(gamma: u64) -> u64 {
+    let mut inv = gamma;
+    for _ in 0..5 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(gamma.wrapping_mul(inv)));
+    }
+    inv
 }
 
 impl RngCore for SMGen {
@@ -131,18 +197,66 @@ impl SeedableRng for SMGenClone {
     }
 }
 
+/// A reference-compatible SplitMix64 generator
+///
+/// Unlike [`SMGen`], which follows the OOPSLA paper's per-generator-gamma design, `SplitMix64`
+/// reproduces the widely-used reference implementation
+/// [`splitmix64.c`](http://prng.di.unimi.it/splitmix64.c): a single `u64` state advanced by the
+/// fixed increment `GOLDEN_GAMMA`, with the Stafford variant 13 mixer applied to the state before
+/// it is returned. This is the canonical seeder for the xoshiro/xoroshiro family of generators, so
+/// this type exists to give a bit-exact reproduction of the C/Java reference stream.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SplitMix64 {
+    x: u64,
+}
+
+impl RngCore for SplitMix64 {
+    fn next_u32(&mut self) -> u32 {
+        self.x = self.x.wrapping_add(GOLDEN_GAMMA);
+        let z = (self.x ^ (self.x >> 33)).wrapping_mul(0x62A9D9ED799705F5);
+        (shift_xor(28, z).wrapping_mul(0xCB24D0A5C88C35B3) >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.x = self.x.wrapping_add(GOLDEN_GAMMA);
+        mix64_variant_13(self.x)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for SplitMix64 {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut dst: [u64; 1] = [1];
+        read_u64_into(&seed, &mut dst);
+        SeedableRng::seed_from_u64(dst[0])
+    }
+
+    fn seed_from_u64(seed: u64) -> Self {
+        SplitMix64 { x: seed }
+    }
+}
+
 fn mix64(z: u64) -> u64 {
     let z = shift_xor_mult(33, 0xff51afd7ed558ccd, z);
     let z = shift_xor_mult(33, 0xc4ceb9fe1a85ec53, z);
-    let z = shift_xor(31, z);
-    z
+    shift_xor(31, z)
 }
 
 fn mix64_variant_13(z: u64) -> u64 {
     let z = shift_xor_mult(30, 0xbf58476d1ce4e5b9, z);
     let z = shift_xor_mult(27, 0x94d049bb133111eb, z);
-    let z = shift_xor(31, z);
-    z
+    shift_xor(31, z)
 }
 
 fn mix_gamma(z: u64) -> u64 {
@@ -162,3 +276,115 @@ fn shift_xor(n: u32, w: u64) -> u64 {
 fn shift_xor_mult(n: u32, k: u64, w: u64) -> u64 {
     shift_xor(n, w).wrapping_mul(k)
 }
+
+#[cfg(test)]
+mod smgen_split_iter_tests {
+    use super::SMGen;
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn split_iter_matches_repeated_split_calls() {
+        let mut by_split = SMGen::seed_from_u64(11);
+        let mut by_iter = by_split.clone();
+
+        let manual: Vec<_> = (0..5).map(|_| by_split.split()).collect();
+        let iterated: Vec<_> = by_iter.split_iter().take(5).collect();
+
+        assert_eq!(manual, iterated);
+        assert_eq!(by_split, by_iter);
+    }
+}
+
+#[cfg(test)]
+mod splitmix64_tests {
+    use super::SplitMix64;
+    use rand_core::{RngCore, SeedableRng};
+
+    /// First three `next_u64` outputs of the reference `splitmix64.c`, seeded with 0
+    #[test]
+    fn next_u64_matches_reference_stream() {
+        let mut rng = SplitMix64::seed_from_u64(0);
+        assert_eq!(rng.next_u64(), 16294208416658607535);
+        assert_eq!(rng.next_u64(), 7960286522194355700);
+        assert_eq!(rng.next_u64(), 487617019471545679);
+    }
+
+    /// First three `next_u32` outputs of the dsiutils-derived 32-bit variant of
+    /// `splitmix64.c`, seeded with 0
+    #[test]
+    fn next_u32_matches_reference_stream() {
+        let mut rng = SplitMix64::seed_from_u64(0);
+        assert_eq!(rng.next_u32(), 821115357);
+        assert_eq!(rng.next_u32(), 1660418793);
+        assert_eq!(rng.next_u32(), 2595562075);
+    }
+}
+
+#[cfg(test)]
+mod smgen_jump_tests {
+    use super::SMGen;
+    use rand_core::{RngCore, SeedableRng};
+
+    #[test]
+    fn advance_matches_stepwise_next_u64() {
+        let mut stepped = SMGen::seed_from_u64(42);
+        let mut jumped = stepped.clone();
+        for _ in 0..100 {
+            stepped.next_u64();
+        }
+        jumped.advance(100);
+        assert_eq!(stepped, jumped);
+    }
+
+    #[test]
+    fn rewind_undoes_advance() {
+        let original = SMGen::seed_from_u64(7);
+        let mut gen = original.clone();
+        gen.advance(12345);
+        gen.rewind(12345);
+        assert_eq!(gen, original);
+    }
+
+    #[test]
+    fn distance_recovers_advance_amount() {
+        let base = SMGen::seed_from_u64(1);
+        let mut advanced = base.clone();
+        advanced.advance(98765);
+        assert_eq!(base.distance(&advanced), Some(98765));
+    }
+
+    #[test]
+    fn distance_is_none_across_unrelated_generators() {
+        let a = SMGen::seed_from_u64(1);
+        let b = SMGen::seed_from_u64(2);
+        assert_eq!(a.distance(&b), None);
+    }
+
+    #[test]
+    fn split_consumes_exactly_two_gamma_steps() {
+        let mut gen = SMGen::seed_from_u64(9);
+        let before = gen.clone();
+        gen.split();
+        assert_eq!(before.distance(&gen), Some(2));
+    }
+
+    #[test]
+    fn advance_splits_matches_repeated_split() {
+        let mut by_split = SMGen::seed_from_u64(3);
+        let mut by_advance = by_split.clone();
+        for _ in 0..10 {
+            by_split.split();
+        }
+        by_advance.advance_splits(10);
+        assert_eq!(by_split, by_advance);
+    }
+
+    #[test]
+    fn rewind_splits_undoes_advance_splits() {
+        let original = SMGen::seed_from_u64(5);
+        let mut gen = original.clone();
+        gen.advance_splits(6);
+        gen.rewind_splits(6);
+        assert_eq!(gen, original);
+    }
+}